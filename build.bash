@@ -128,7 +128,7 @@ fi
 git checkout v"${LIBVPX_VERSION}"
 patch -p1 < ../libwebm.patch
 
-libvpx_configure_options=('--disable-examples' '--disable-tools' '--disable-docs' '--disable-unit-tests' )
+libvpx_configure_options=('--disable-examples' '--disable-tools' '--disable-docs' '--disable-unit-tests' '--enable-error-concealment' '--enable-postproc' '--enable-vp9-postproc' )
 if [ "${BUILD_TYPE}" = "Native" ]; then
     libvpx_configure_options+=('--cpu=native')
 fi
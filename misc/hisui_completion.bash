@@ -16,6 +16,10 @@ _hisui() {
             _filedir
             return
             ;;
+        --background-image)
+            _filedir
+            return
+            ;;
         --out-container)
             mapfile -t COMPREPLY < <(compgen -W 'WebM MP4' -- "$cur")
             return
@@ -44,12 +48,12 @@ _hisui() {
             mapfile -t COMPREPLY < <(compgen -W 'Faststart Simple' -- "$cur")
             return
             ;;
-        -h|--help|--verbose|--show-progress-bar|--out-video-frame-rate|--out-video-bit-rate|--out-opus-bit-rate|--out-aac-bit-rate)
+        -h|--help|--verbose|--show-progress-bar|--out-video-frame-rate|--out-video-bit-rate|--out-opus-bit-rate|--out-aac-bit-rate|--background-color)
             return
             ;;
     esac
 
-    mapfile -t COMPREPLY< <(compgen -W '-h --help -f --in-metadata-file --openh264 --out-container --out-video-codec --out-audio-codec --out-video-frame-rate --out-file --out-file --max-columns --libvpx-cq-level --libvpx-min-q --libvpx-max-q --mp4-muxer --verbose --show-progress-bar --out-video-bit-rate --out-opus-bit-rate --out-aac-bit-rate --libvpx-threads' -- "$cur")
+    mapfile -t COMPREPLY< <(compgen -W '-h --help -f --in-metadata-file --openh264 --out-container --out-video-codec --out-audio-codec --out-video-frame-rate --out-file --out-file --max-columns --background-color --background-image --libvpx-cq-level --libvpx-min-q --libvpx-max-q --mp4-muxer --verbose --show-progress-bar --out-video-bit-rate --out-opus-bit-rate --out-aac-bit-rate --libvpx-threads' -- "$cur")
 }
 
 complete -F _hisui hisui
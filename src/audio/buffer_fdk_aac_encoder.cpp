@@ -42,7 +42,6 @@ void BufferFDKAACEncoder::addSample(const std::int16_t left,
 
   if (std::size(m_pcm_buffer) >= m_max_sample_size) {
     encodeAndWrite();
-    m_timestamp += m_max_sample_size / 2;
   }
 }
 
@@ -50,26 +49,63 @@ void BufferFDKAACEncoder::flush() {
   if (std::size(m_pcm_buffer) > 0) {
     encodeAndWrite();
   }
+  // encoder 内部にバッファされているフレームを ::AACENC_ENCODE_EOF が返るまで出力する
+  while (drainAndWrite()) {
+  }
 }
 
 void BufferFDKAACEncoder::encodeAndWrite() {
+  ::AACENC_OutArgs out_args;
+  const ::AACENC_ERROR err =
+      encode(static_cast<int>(std::size(m_pcm_buffer)), &out_args);
+  if (err != ::AACENC_OK) {
+    if (err == ::AACENC_ENCODE_EOF) {
+      return;
+    }
+    throw std::runtime_error("Encoding failed");
+  }
+
+  // 出力がない場合も入力は encoder 内部にバッファされているので破棄する
+  m_pcm_buffer.clear();
+
+  if (out_args.numOutBytes > 0) {
+    write(static_cast<std::size_t>(out_args.numOutBytes));
+  }
+}
+
+bool BufferFDKAACEncoder::drainAndWrite() {
+  ::AACENC_OutArgs out_args;
+  // numInSamples に -1 を指定すると flush になる
+  const ::AACENC_ERROR err = encode(-1, &out_args);
+  if (err != ::AACENC_OK) {
+    if (err == ::AACENC_ENCODE_EOF) {
+      return false;
+    }
+    throw std::runtime_error("Flushing failed");
+  }
+
+  if (out_args.numOutBytes > 0) {
+    write(static_cast<std::size_t>(out_args.numOutBytes));
+  }
+  return true;
+}
+
+::AACENC_ERROR BufferFDKAACEncoder::encode(const int num_in_samples,
+                                           ::AACENC_OutArgs* out_args) {
   ::AACENC_BufDesc in_buf, out_buf;
   ::AACENC_InArgs in_args;
-  ::AACENC_OutArgs out_args;
   int in_identifier = ::IN_AUDIO_DATA;
   int in_size, in_elem_size;
   int out_identifier = ::OUT_BITSTREAM_DATA;
   int out_size, out_elem_size;
   void *in_ptr, *out_ptr;
-  ::AACENC_ERROR err;
-
-  const int num_in_samples = static_cast<int>(std::size(m_pcm_buffer));
 
   in_ptr = m_pcm_buffer.data();
-  in_size = num_in_samples * 2;
+  in_size = num_in_samples > 0 ? num_in_samples * 2 : 0;
   in_elem_size = 2;
 
   in_args.numInSamples = num_in_samples;
+  in_args.numAncBytes = 0;
   in_buf.numBufs = 1;
   in_buf.bufs = &in_ptr;
   in_buf.bufferIdentifiers = &in_identifier;
@@ -85,28 +121,18 @@ void BufferFDKAACEncoder::encodeAndWrite() {
   out_buf.bufSizes = &out_size;
   out_buf.bufElSizes = &out_elem_size;
 
-  if ((err = ::aacEncEncode(m_handle, &in_buf, &out_buf, &in_args,
-                            &out_args)) != ::AACENC_OK) {
-    if (err == ::AACENC_ENCODE_EOF) {
-      return;
-    }
-    throw std::runtime_error("Encoding failed");
-  }
-
-  if (out_args.numOutBytes == 0) {
-    return;
-  }
-
-  const std::size_t data_size = static_cast<std::size_t>(out_args.numOutBytes);
+  return ::aacEncEncode(m_handle, &in_buf, &out_buf, &in_args, out_args);
+}
 
+void BufferFDKAACEncoder::write(const std::size_t data_size) {
   std::uint8_t* data = new std::uint8_t[data_size];
   std::copy_n(m_aac_buffer, data_size, data);
   m_buffer->push(hisui::Frame{.timestamp = m_timestamp,
                               .data = data,
                               .data_size = data_size,
                               .is_key = true});
-
-  m_pcm_buffer.clear();
+  // timestamp はフレームを出力したときだけ進める
+  m_timestamp += m_max_sample_size / 2;
 }
 
 }  // namespace hisui::audio
@@ -2,6 +2,7 @@
 
 #include <fdk-aac/aacenc_lib.h>
 
+#include <cstddef>
 #include <cstdint>
 #include <queue>
 #include <vector>
@@ -37,6 +38,9 @@ class BufferFDKAACEncoder : public Encoder {
   std::uint64_t m_timestamp = 0;
 
   void encodeAndWrite();
+  bool drainAndWrite();
+  ::AACENC_ERROR encode(const int, ::AACENC_OutArgs*);
+  void write(const std::size_t);
 };
 
 }  // namespace hisui::audio
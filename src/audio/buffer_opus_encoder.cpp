@@ -25,7 +25,11 @@ BufferOpusEncoder::BufferOpusEncoder(std::queue<hisui::Frame>* t_buffer,
       m_timestamp_step(
           static_cast<std::uint64_t>(hisui::Constants::OPUS_ENCODE_FRAME_SIZE) *
           m_timescale / hisui::Constants::PCM_SAMPLE_RATE) {
-  m_encoder = create_opus_encoder({.bit_rate = params.bit_rate});
+  m_encoder = create_opus_encoder({.bit_rate = params.bit_rate,
+                                   .application = params.application,
+                                   .complexity = params.complexity,
+                                   .max_bandwidth = params.max_bandwidth,
+                                   .signal = params.signal});
 
   const int ret = ::opus_encoder_ctl(m_encoder, OPUS_GET_LOOKAHEAD(&m_skip));
   if (ret < 0) {
@@ -21,6 +21,10 @@ namespace hisui::audio {
 struct BufferOpusEncoderParameters {
   const std::uint32_t bit_rate;
   const std::uint64_t timescale = hisui::Constants::NANO_SECOND;
+  const int application = OPUS_APPLICATION_AUDIO;
+  const int complexity = -1;
+  const int max_bandwidth = OPUS_AUTO;
+  const int signal = OPUS_AUTO;
 };
 
 class BufferOpusEncoder : public Encoder {
@@ -40,7 +40,7 @@ std::array<std::uint8_t, 19> create_opus_private_data(
   int err;
   ::OpusEncoder* encoder =
       ::opus_encoder_create(static_cast<opus_int32>(params.sample_rate),
-                            params.channels, OPUS_APPLICATION_AUDIO, &err);
+                            params.channels, params.application, &err);
   if (err < 0) {
     throw std::runtime_error(fmt::format(
         "opus_encoder_create() failed: error='{}'", ::opus_strerror(err)));
@@ -54,6 +54,36 @@ std::array<std::uint8_t, 19> create_opus_private_data(
                     ::opus_strerror(encoder_ctl_ret)));
   }
 
+  if (params.complexity >= 0) {
+    const int complexity_ret =
+        ::opus_encoder_ctl(encoder, OPUS_SET_COMPLEXITY(params.complexity));
+    if (complexity_ret < 0) {
+      throw std::runtime_error(
+          fmt::format("opus_encoder_ctl(COMPLEXITY) failed: error='{}'",
+                      ::opus_strerror(complexity_ret)));
+    }
+  }
+
+  if (params.max_bandwidth != OPUS_AUTO) {
+    const int max_bandwidth_ret = ::opus_encoder_ctl(
+        encoder, OPUS_SET_MAX_BANDWIDTH(params.max_bandwidth));
+    if (max_bandwidth_ret < 0) {
+      throw std::runtime_error(
+          fmt::format("opus_encoder_ctl(MAX_BANDWIDTH) failed: error='{}'",
+                      ::opus_strerror(max_bandwidth_ret)));
+    }
+  }
+
+  if (params.signal != OPUS_AUTO) {
+    const int signal_ret =
+        ::opus_encoder_ctl(encoder, OPUS_SET_SIGNAL(params.signal));
+    if (signal_ret < 0) {
+      throw std::runtime_error(
+          fmt::format("opus_encoder_ctl(SIGNAL) failed: error='{}'",
+                      ::opus_strerror(signal_ret)));
+    }
+  }
+
   return encoder;
 }
 
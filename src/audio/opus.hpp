@@ -22,6 +22,10 @@ struct CreateOpusEncoderParameters {
   const std::uint32_t sample_rate = hisui::Constants::PCM_SAMPLE_RATE;
   const int channels = 2;
   const std::uint32_t bit_rate = hisui::Constants::OPUS_DEFAULT_BIT_RATE;
+  const int application = OPUS_APPLICATION_AUDIO;
+  const int complexity = -1;  // 負の値の場合は libopus のデフォルトを利用する
+  const int max_bandwidth = OPUS_AUTO;
+  const int signal = OPUS_AUTO;
 };
 
 ::OpusEncoder* create_opus_encoder(const CreateOpusEncoderParameters&);
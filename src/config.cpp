@@ -3,7 +3,10 @@
 #include <libyuv/scale.h>
 #include <spdlog/common.h>
 
+#include <algorithm>
+#include <cctype>
 #include <functional>
+#include <iterator>
 #include <memory>
 #include <stdexcept>
 #include <type_traits>
@@ -53,6 +56,37 @@ struct NonNegativeMultipleOf4Validator : public CLI::Validator {
 
 const NonNegativeMultipleOf4Validator NonNegativeMultipleOf4;
 
+// "#RRGGBB" もしくは "RRGGBB" 形式の色を RGB に変換する
+bool parse_rgb_color(const std::string& color_str,
+                     std::array<std::uint8_t, 3>* rgb) {
+  const std::string hex =
+      color_str.starts_with('#') ? color_str.substr(1) : color_str;
+  if (std::size(hex) != 6 ||
+      !std::all_of(std::begin(hex), std::end(hex),
+                   [](unsigned char c) { return std::isxdigit(c); })) {
+    return false;
+  }
+  for (std::size_t i = 0; i < 3; ++i) {
+    (*rgb)[i] = static_cast<std::uint8_t>(
+        std::stoul(hex.substr(i * 2, 2), nullptr, 16));
+  }
+  return true;
+}
+
+struct RGBColorValidator : public CLI::Validator {
+  RGBColorValidator() : Validator("#RRGGBB") {
+    func_ = [](std::string& color_str) {
+      std::array<std::uint8_t, 3> rgb;
+      if (!parse_rgb_color(color_str, &rgb)) {
+        return std::string("Failed parsing color: (") + color_str + ')';
+      }
+      return std::string();
+    };
+  }
+};
+
+const RGBColorValidator RGBColor;
+
 }  // namespace
 
 namespace hisui {
@@ -142,6 +176,21 @@ void set_cli_options(CLI::App* app, Config* config) {
                   "Max columns (POSITIVE INTEGER). default: 3")
       ->check(CLI::PositiveNumber);
 
+  app->add_option_function<std::string>(
+         "--background-color",
+         [config](const std::string& color_str) {
+           std::array<std::uint8_t, 3> rgb;
+           parse_rgb_color(color_str, &rgb);
+           config->background_color = rgb;
+         },
+         "Background color of empty cells, margins and absent videos "
+         "(#RRGGBB). default: full-range black (YUV 0,128,128)")
+      ->check(RGBColor);
+  app->add_option("--background-image", config->background_image,
+                  "Background image of empty cells (PNG/JPEG). "
+                  "scaled to the output size")
+      ->check(CLI::ExistingFile);
+
   app->add_option(
          "--libvpx-cq-level", config->libvpx_cq_level,
          "libvpx Constrained Quality level (NON NEGATIVE INTEGER). default: 30")
@@ -258,6 +307,94 @@ void set_cli_options(CLI::App* app, Config* config) {
       ->check(CLI::Range(0, 1))
       ->group(OPTIONS_FOR_TUNING);
 
+  app->add_flag("--libvpx-error-concealment", config->libvpx_error_concealment,
+                "libvpx error concealment on decoding (VP8 only). "
+                "default: false")
+      ->group(OPTIONS_FOR_TUNING);
+
+  app->add_option("--libvpx-postproc-deblocking-level",
+                  config->libvpx_postproc_deblocking_level,
+                  "libvpx deblocking level of post processing on decoding. "
+                  "default: 0 (disabled) (0, 16)")
+      ->check(CLI::Range(0, 16))
+      ->group(OPTIONS_FOR_TUNING);
+
+  app->add_option("--libvpx-postproc-noise-level",
+                  config->libvpx_postproc_noise_level,
+                  "libvpx noise level of post processing on decoding. "
+                  "default: 0 (disabled) (0, 16)")
+      ->check(CLI::Range(0, 16))
+      ->group(OPTIONS_FOR_TUNING);
+
+  std::vector<std::pair<std::string, config::OpusApplication>>
+      opus_application_assoc{
+          {"audio", config::OpusApplication::Audio},
+          {"voip", config::OpusApplication::VoIP},
+          {"restricted-lowdelay", config::OpusApplication::RestrictedLowDelay},
+      };
+  app->add_option("--opus-application", config->opus_application,
+                  "Opus application (audio/voip/restricted-lowdelay). "
+                  "default: audio")
+      ->transform(
+          CLI::CheckedTransformer(opus_application_assoc, CLI::ignore_case))
+      ->group(OPTIONS_FOR_TUNING);
+
+  app->add_option("--opus-complexity", config->opus_complexity,
+                  "Opus encoder computational complexity (-1, 10). "
+                  "default: -1 (use default)")
+      ->check(CLI::Range(-1, 10))
+      ->group(OPTIONS_FOR_TUNING);
+
+  std::vector<std::pair<std::string, config::OpusBandwidth>>
+      opus_bandwidth_assoc{
+          {"auto", config::OpusBandwidth::Auto},
+          {"narrowband", config::OpusBandwidth::Narrowband},
+          {"mediumband", config::OpusBandwidth::Mediumband},
+          {"wideband", config::OpusBandwidth::Wideband},
+          {"superwideband", config::OpusBandwidth::Superwideband},
+          {"fullband", config::OpusBandwidth::Fullband},
+      };
+  app->add_option("--opus-max-bandwidth", config->opus_max_bandwidth,
+                  "Opus encoder max bandwidth "
+                  "(auto/narrowband/mediumband/wideband/superwideband/"
+                  "fullband). default: auto")
+      ->transform(
+          CLI::CheckedTransformer(opus_bandwidth_assoc, CLI::ignore_case))
+      ->group(OPTIONS_FOR_TUNING);
+
+  std::vector<std::pair<std::string, config::OpusSignal>> opus_signal_assoc{
+      {"auto", config::OpusSignal::Auto},
+      {"voice", config::OpusSignal::Voice},
+      {"music", config::OpusSignal::Music},
+  };
+  app->add_option("--opus-signal", config->opus_signal,
+                  "Opus encoder signal type hint (auto/voice/music). "
+                  "default: auto")
+      ->transform(CLI::CheckedTransformer(opus_signal_assoc, CLI::ignore_case))
+      ->group(OPTIONS_FOR_TUNING);
+
+  std::vector<std::pair<std::string, config::OpenH264ErrorConcealment>>
+      openh264_error_concealment_assoc{
+          {"disable", config::OpenH264ErrorConcealment::Disable},
+          {"frame-copy", config::OpenH264ErrorConcealment::FrameCopy},
+          {"slice-copy", config::OpenH264ErrorConcealment::SliceCopy},
+          {"frame-copy-cross-idr",
+           config::OpenH264ErrorConcealment::FrameCopyCrossIDR},
+          {"slice-copy-cross-idr",
+           config::OpenH264ErrorConcealment::SliceCopyCrossIDR},
+          {"slice-mv-copy-cross-idr",
+           config::OpenH264ErrorConcealment::SliceMVCopyCrossIDR},
+      };
+  app->add_option("--openh264-error-concealment",
+                  config->openh264_error_concealment,
+                  "OpenH264 error concealment on decoding "
+                  "(disable/frame-copy/slice-copy/frame-copy-cross-idr/"
+                  "slice-copy-cross-idr/slice-mv-copy-cross-idr). "
+                  "default: disable")
+      ->transform(CLI::CheckedTransformer(openh264_error_concealment_assoc,
+                                          CLI::ignore_case))
+      ->group(OPTIONS_FOR_TUNING);
+
   std::vector<std::pair<std::string, spdlog::level::level_enum>>
       log_level_assoc{
           {"trace", spdlog::level::trace},
@@ -3,8 +3,10 @@
 #include <libyuv/scale.h>
 #include <spdlog/common.h>
 
+#include <array>
 #include <cstddef>
 #include <cstdint>
+#include <optional>
 #include <string>
 
 #include <boost/cstdint.hpp>
@@ -57,6 +59,36 @@ enum struct OutAudioCodec {
   FDK_AAC,
 };
 
+enum struct OpusApplication {
+  Audio,
+  VoIP,
+  RestrictedLowDelay,
+};
+
+enum struct OpusBandwidth {
+  Auto,
+  Narrowband,
+  Mediumband,
+  Wideband,
+  Superwideband,
+  Fullband,
+};
+
+enum struct OpusSignal {
+  Auto,
+  Voice,
+  Music,
+};
+
+enum struct OpenH264ErrorConcealment {
+  Disable,
+  FrameCopy,
+  SliceCopy,
+  FrameCopyCrossIDR,
+  SliceCopyCrossIDR,
+  SliceMVCopyCrossIDR,
+};
+
 }  // namespace config
 
 class Config {
@@ -79,12 +111,18 @@ class Config {
   std::uint32_t libvpx_min_q = 10;
   std::uint32_t libvpx_max_q = 50;
   std::uint32_t out_opus_bit_rate = Constants::OPUS_DEFAULT_BIT_RATE;
+  config::OpusApplication opus_application = config::OpusApplication::Audio;
+  std::int32_t opus_complexity = -1;
+  config::OpusBandwidth opus_max_bandwidth = config::OpusBandwidth::Auto;
+  config::OpusSignal opus_signal = config::OpusSignal::Auto;
   std::uint32_t out_aac_bit_rate = Constants::FDK_AAC_DEFAULT_BIT_RATE;
 
   std::string out_filename = "";
   std::string directory_for_faststart_intermediate_file = "";
 
   std::size_t max_columns = 3;
+  std::optional<std::array<std::uint8_t, 3>> background_color;
+  std::string background_image = "";
 
   bool verbose = false;
   bool audio_only = false;
@@ -111,12 +149,17 @@ class Config {
   std::uint32_t libvp9_frame_parallel = 1;
   std::uint32_t libvp9_tile_columns = 0;
   std::uint32_t libvp9_row_mt = 0;
+  bool libvpx_error_concealment = false;
+  std::uint32_t libvpx_postproc_deblocking_level = 0;
+  std::uint32_t libvpx_postproc_noise_level = 0;
 
   libyuv::FilterMode libyuv_filter_mode = libyuv::kFilterBox;
 
   config::VideoComposer video_composer = config::VideoComposer::Grid;
   config::VideoScaler video_scaler = config::VideoScaler::PreserveAspectRatio;
   std::string openh264 = "";
+  config::OpenH264ErrorConcealment openh264_error_concealment =
+      config::OpenH264ErrorConcealment::Disable;
 
   config::AudioMixer audio_mixer = config::AudioMixer::Simple;
   bool mix_screen_capture_audio = false;
@@ -24,6 +24,7 @@
 #include "video/parallel_grid_composer.hpp"
 #include "video/sequencer.hpp"
 #include "video/vpx.hpp"
+#include "video/yuv.hpp"
 
 namespace hisui::video {
 
@@ -42,7 +43,7 @@ MultiChannelVPXVideoProducer::MultiChannelVPXVideoProducer(
       m_preferred_bit_rate(t_config.screen_capture_bit_rate) {
   m_sequencer = new hisui::video::MultiChannelSequencer(
       t_metadata_set.getNormal().getArchives(),
-      t_metadata_set.getPreferred().getArchives());
+      t_metadata_set.getPreferred().getArchives(), t_config);
 
   const auto scaling_width = t_config.scaling_width != 0
                                  ? t_config.scaling_width
@@ -51,27 +52,39 @@ MultiChannelVPXVideoProducer::MultiChannelVPXVideoProducer(
                                   ? t_config.scaling_height
                                   : m_sequencer->getMaxHeight();
 
+  const auto background_plane_values =
+      hisui::video::get_background_plane_values(t_config.background_color);
+
   switch (t_config.video_composer) {
     case hisui::config::VideoComposer::Grid:
       m_normal_channel_composer = new hisui::video::GridComposer(
           scaling_width, scaling_height, m_sequencer->getSize(),
           t_config.max_columns, t_config.video_scaler,
-          t_config.libyuv_filter_mode);
+          t_config.libyuv_filter_mode, background_plane_values);
       m_preferred_channel_composer = new hisui::video::GridComposer(
           t_config.screen_capture_width, t_config.screen_capture_height, 1, 1,
-          t_config.video_scaler, t_config.libyuv_filter_mode);
+          t_config.video_scaler, t_config.libyuv_filter_mode,
+          background_plane_values);
       break;
     case hisui::config::VideoComposer::ParallelGrid:
       m_normal_channel_composer = new hisui::video::ParallelGridComposer(
           scaling_width, scaling_height, m_sequencer->getSize(),
           t_config.max_columns, t_config.video_scaler,
-          t_config.libyuv_filter_mode);
+          t_config.libyuv_filter_mode, background_plane_values);
       m_preferred_channel_composer = new hisui::video::GridComposer(
           t_config.screen_capture_width, t_config.screen_capture_height, 1, 1,
-          t_config.video_scaler, t_config.libyuv_filter_mode);
+          t_config.video_scaler, t_config.libyuv_filter_mode,
+          background_plane_values);
       break;
   }
 
+  if (!t_config.background_image.empty()) {
+    m_normal_channel_composer->setBackgroundImage(
+        t_config.background_image, t_config.libyuv_filter_mode);
+    m_preferred_channel_composer->setBackgroundImage(
+        t_config.background_image, t_config.libyuv_filter_mode);
+  }
+
   m_composer = m_normal_channel_composer;
 
   hisui::video::VPXEncoderConfig vpx_config(
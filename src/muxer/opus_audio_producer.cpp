@@ -1,5 +1,6 @@
 #include "muxer/opus_audio_producer.hpp"
 
+#include <opus_defines.h>
 #include <opus_types.h>
 
 #include "audio/basic_sequencer.hpp"
@@ -11,6 +12,52 @@
 
 namespace hisui::muxer {
 
+namespace {
+
+int get_opus_application(const hisui::config::OpusApplication application) {
+  switch (application) {
+    case hisui::config::OpusApplication::Audio:
+      return OPUS_APPLICATION_AUDIO;
+    case hisui::config::OpusApplication::VoIP:
+      return OPUS_APPLICATION_VOIP;
+    case hisui::config::OpusApplication::RestrictedLowDelay:
+      return OPUS_APPLICATION_RESTRICTED_LOWDELAY;
+  }
+  return OPUS_APPLICATION_AUDIO;
+}
+
+int get_opus_bandwidth(const hisui::config::OpusBandwidth bandwidth) {
+  switch (bandwidth) {
+    case hisui::config::OpusBandwidth::Auto:
+      return OPUS_AUTO;
+    case hisui::config::OpusBandwidth::Narrowband:
+      return OPUS_BANDWIDTH_NARROWBAND;
+    case hisui::config::OpusBandwidth::Mediumband:
+      return OPUS_BANDWIDTH_MEDIUMBAND;
+    case hisui::config::OpusBandwidth::Wideband:
+      return OPUS_BANDWIDTH_WIDEBAND;
+    case hisui::config::OpusBandwidth::Superwideband:
+      return OPUS_BANDWIDTH_SUPERWIDEBAND;
+    case hisui::config::OpusBandwidth::Fullband:
+      return OPUS_BANDWIDTH_FULLBAND;
+  }
+  return OPUS_AUTO;
+}
+
+int get_opus_signal(const hisui::config::OpusSignal signal) {
+  switch (signal) {
+    case hisui::config::OpusSignal::Auto:
+      return OPUS_AUTO;
+    case hisui::config::OpusSignal::Voice:
+      return OPUS_SIGNAL_VOICE;
+    case hisui::config::OpusSignal::Music:
+      return OPUS_SIGNAL_MUSIC;
+  }
+  return OPUS_AUTO;
+}
+
+}  // namespace
+
 OpusAudioProducer::OpusAudioProducer(const hisui::Config& t_config,
                                      const hisui::MetadataSet& t_metadata_set,
                                      const std::uint64_t timescale)
@@ -32,7 +79,12 @@ OpusAudioProducer::OpusAudioProducer(const hisui::Config& t_config,
   hisui::audio::BufferOpusEncoder* encoder =
       new hisui::audio::BufferOpusEncoder(
           &m_buffer,
-          {.bit_rate = t_config.out_opus_bit_rate, .timescale = timescale});
+          {.bit_rate = t_config.out_opus_bit_rate,
+           .timescale = timescale,
+           .application = get_opus_application(t_config.opus_application),
+           .complexity = t_config.opus_complexity,
+           .max_bandwidth = get_opus_bandwidth(t_config.opus_max_bandwidth),
+           .signal = get_opus_signal(t_config.opus_signal)});
   m_skip = encoder->getSkip();
   m_encoder = encoder;
 }
@@ -14,6 +14,7 @@
 #include "video/parallel_grid_composer.hpp"
 #include "video/sequencer.hpp"
 #include "video/vpx.hpp"
+#include "video/yuv.hpp"
 
 namespace hisui::muxer {
 
@@ -21,7 +22,8 @@ VPXVideoProducer::VPXVideoProducer(const hisui::Config& t_config,
                                    const hisui::Metadata& t_metadata,
                                    const std::uint64_t timescale)
     : VideoProducer({.show_progress_bar = t_config.show_progress_bar}) {
-  m_sequencer = new hisui::video::BasicSequencer(t_metadata.getArchives());
+  m_sequencer = new hisui::video::BasicSequencer(t_metadata.getArchives(),
+                                                 t_config);
 
   const auto scaling_width = t_config.scaling_width != 0
                                  ? t_config.scaling_width
@@ -30,21 +32,29 @@ VPXVideoProducer::VPXVideoProducer(const hisui::Config& t_config,
                                   ? t_config.scaling_height
                                   : m_sequencer->getMaxHeight();
 
+  const auto background_plane_values =
+      hisui::video::get_background_plane_values(t_config.background_color);
+
   switch (t_config.video_composer) {
     case hisui::config::VideoComposer::Grid:
       m_composer = new hisui::video::GridComposer(
           scaling_width, scaling_height, m_sequencer->getSize(),
           t_config.max_columns, t_config.video_scaler,
-          t_config.libyuv_filter_mode);
+          t_config.libyuv_filter_mode, background_plane_values);
       break;
     case hisui::config::VideoComposer::ParallelGrid:
       m_composer = new hisui::video::ParallelGridComposer(
           scaling_width, scaling_height, m_sequencer->getSize(),
           t_config.max_columns, t_config.video_scaler,
-          t_config.libyuv_filter_mode);
+          t_config.libyuv_filter_mode, background_plane_values);
       break;
   }
 
+  if (!t_config.background_image.empty()) {
+    m_composer->setBackgroundImage(t_config.background_image,
+                                   t_config.libyuv_filter_mode);
+  }
+
   hisui::video::VPXEncoderConfig vpx_config(m_composer->getWidth(),
                                             m_composer->getHeight(), t_config);
 
@@ -11,6 +11,7 @@
 #include <string>
 #include <utility>
 
+#include "config.hpp"
 #include "util/interval.hpp"
 #include "video/sequencer.hpp"
 #include "video/source.hpp"
@@ -18,8 +19,9 @@
 
 namespace hisui::video {
 
-BasicSequencer::BasicSequencer(const std::vector<hisui::Archive>& archives) {
-  auto result = make_sequence(archives);
+BasicSequencer::BasicSequencer(const std::vector<hisui::Archive>& archives,
+                               const hisui::Config& config) {
+  auto result = make_sequence(archives, config);
 
   m_sequence = result.sequence;
   m_size = std::size(m_sequence);
@@ -31,11 +33,14 @@ BasicSequencer::BasicSequencer(const std::vector<hisui::Archive>& archives) {
   spdlog::debug("m_max_width x m_max_height: {} x {}", m_max_width,
                 m_max_height);
 
-  m_black_yuv_image = create_black_yuv_image(m_max_width, m_max_height);
+  // アーカイブがない区間は背景色で埋める
+  m_background_yuv_image = create_background_yuv_image(
+      m_max_width, m_max_height,
+      get_background_plane_values(config.background_color));
 }  // namespace hisui::video
 
 BasicSequencer::~BasicSequencer() {
-  delete m_black_yuv_image;
+  delete m_background_yuv_image;
 }
 
 SequencerGetYUVsResult BasicSequencer::getYUVs(
@@ -47,7 +52,7 @@ SequencerGetYUVsResult BasicSequencer::getYUVs(
         std::begin(*p.second), std::end(*p.second),
         [timestamp](const auto& s) { return s.second.isIn(timestamp); });
     if (it == std::end(*p.second)) {
-      (*yuvs)[i] = m_black_yuv_image;
+      (*yuvs)[i] = m_background_yuv_image;
     } else {
       (*yuvs)[i] = it->first->getYUV(it->second.getSubstructLower(timestamp));
     }
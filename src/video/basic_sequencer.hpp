@@ -8,6 +8,7 @@
 namespace hisui {
 
 class Archive;
+class Config;
 
 }
 
@@ -17,14 +18,14 @@ class YUVImage;
 
 class BasicSequencer : public Sequencer {
  public:
-  explicit BasicSequencer(const std::vector<hisui::Archive>&);
+  BasicSequencer(const std::vector<hisui::Archive>&, const hisui::Config&);
   ~BasicSequencer();
 
   SequencerGetYUVsResult getYUVs(std::vector<const YUVImage*>*,
                                  const std::uint64_t);
 
  private:
-  const YUVImage* m_black_yuv_image;
+  const YUVImage* m_background_yuv_image;
 };
 
 }  // namespace hisui::video
@@ -1,7 +1,19 @@
 #include "video/composer.hpp"
 
+#include <algorithm>
+
+#include "video/image_source.hpp"
+#include "video/simple_scaler.hpp"
+#include "video/yuv.hpp"
+
 namespace hisui::video {
 
+Composer::~Composer() {
+  if (m_background_image) {
+    delete m_background_image;
+  }
+}
+
 std::uint32_t Composer::getWidth() const {
   return m_width;
 }
@@ -10,4 +22,29 @@ std::uint32_t Composer::getHeight() const {
   return m_height;
 }
 
+void Composer::setBackgroundImage(const std::string& file_path,
+                                  const libyuv::FilterMode filter_mode) {
+  // 合成後の大きさに拡大縮小して, 合成のベースとして使う
+  ImageSource image_source(file_path);
+  SimpleScaler scaler(m_width, m_height, filter_mode);
+  const auto scaled = scaler.scale(image_source.getYUV(0));
+
+  if (m_background_image) {
+    delete m_background_image;
+  }
+  m_background_image = new YUVImage(m_width, m_height);
+  for (std::size_t p = 0; p < 3; ++p) {
+    const auto i = static_cast<int>(p);
+    std::copy_n(scaled->yuv[p], scaled->getWidth(i) * scaled->getHeight(i),
+                m_background_image->yuv[p]);
+  }
+}
+
+const unsigned char* Composer::getBackgroundPlane(const std::size_t p) const {
+  if (m_background_image) {
+    return m_background_image->yuv[p];
+  }
+  return nullptr;
+}
+
 }  // namespace hisui::video
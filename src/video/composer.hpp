@@ -1,6 +1,10 @@
 #pragma once
 
+#include <libyuv/scale.h>
+
+#include <cstddef>
 #include <cstdint>
+#include <string>
 #include <vector>
 
 namespace hisui::video {
@@ -9,16 +13,21 @@ class YUVImage;
 
 class Composer {
  public:
-  virtual ~Composer() = default;
+  virtual ~Composer();
   virtual void compose(std::vector<unsigned char>*,
                        const std::vector<const YUVImage*>&) = 0;
 
   std::uint32_t getWidth() const;
   std::uint32_t getHeight() const;
 
+  void setBackgroundImage(const std::string&, const libyuv::FilterMode);
+
  protected:
   std::uint32_t m_width;
   std::uint32_t m_height;
+  YUVImage* m_background_image = nullptr;
+
+  const unsigned char* getBackgroundPlane(const std::size_t) const;
 };
 
 }  // namespace hisui::video
@@ -1,20 +1,24 @@
 #include "video/decoder.hpp"
 
+#include "config.hpp"
 #include "video/yuv.hpp"
 #include "webm/input/video_context.hpp"
 
 namespace hisui::video {
 
-Decoder::Decoder(hisui::webm::input::VideoContext* t_webm) : m_webm(t_webm) {
+Decoder::Decoder(hisui::webm::input::VideoContext* t_webm,
+                 const hisui::Config& config)
+    : m_webm(t_webm) {
   m_width = m_webm->getWidth();
   m_height = m_webm->getHeight();
   m_duration = static_cast<std::uint64_t>(m_webm->getDuration());
-  m_black_yuv_image = create_black_yuv_image(m_width, m_height);
+  m_background_yuv_image = create_background_yuv_image(
+      m_width, m_height, get_background_plane_values(config.background_color));
 }
 
 Decoder::~Decoder() {
-  if (m_black_yuv_image) {
-    delete m_black_yuv_image;
+  if (m_background_yuv_image) {
+    delete m_background_yuv_image;
   }
 }
 
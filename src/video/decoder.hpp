@@ -2,6 +2,10 @@
 
 #include <cstdint>
 
+namespace hisui {
+class Config;
+}
+
 namespace hisui::webm::input {
 class VideoContext;
 }
@@ -12,7 +16,7 @@ class YUVImage;
 
 class Decoder {
  public:
-  explicit Decoder(hisui::webm::input::VideoContext*);
+  Decoder(hisui::webm::input::VideoContext*, const hisui::Config&);
 
   virtual ~Decoder();
   virtual const YUVImage* getImage(const std::uint64_t) = 0;
@@ -27,7 +31,7 @@ class Decoder {
   bool m_finished_webm = false;
   std::uint32_t m_width;
   std::uint32_t m_height;
-  YUVImage* m_black_yuv_image;
+  YUVImage* m_background_yuv_image;
 };
 
 }  // namespace hisui::video
@@ -16,7 +16,8 @@ GridComposer::GridComposer(const std::uint32_t t_single_width,
                            const std::size_t t_size,
                            const std::size_t t_colomn,
                            const hisui::config::VideoScaler& scaler_type,
-                           const libyuv::FilterMode filter_mode)
+                           const libyuv::FilterMode filter_mode,
+                           const std::array<unsigned char, 3>& t_background)
     : m_single_width(t_single_width),
       m_single_height(t_single_height),
       m_size(t_size),
@@ -29,7 +30,7 @@ GridComposer::GridComposer(const std::uint32_t t_single_width,
     switch (scaler_type) {
       case hisui::config::VideoScaler::PreserveAspectRatio:
         m_scalers.push_back(std::make_unique<PreserveAspectRatioScaler>(
-            m_single_width, m_single_height, filter_mode));
+            m_single_width, m_single_height, filter_mode, t_background));
         break;
       case hisui::config::VideoScaler::Simple:
         m_scalers.push_back(std::make_unique<SimpleScaler>(
@@ -52,9 +53,7 @@ GridComposer::GridComposer(const std::uint32_t t_single_width,
   m_single_plane_heights[0] = m_single_height;
   m_single_plane_heights[1] = (m_single_height + 1) >> 1;
   m_single_plane_heights[2] = m_single_plane_heights[1];
-  m_plane_default_values[0] = 0;
-  m_plane_default_values[1] = 128;
-  m_plane_default_values[2] = 128;
+  m_plane_default_values = t_background;
 }
 
 GridComposer::~GridComposer() {
@@ -77,7 +76,8 @@ void GridComposer::compose(std::vector<unsigned char>* composed,
     merge_yuv_planes_from_top_left(m_planes[p], m_plane_sizes[p], m_column,
                                    m_srcs, m_size, m_single_plane_widths[p],
                                    m_single_plane_heights[p],
-                                   m_plane_default_values[p]);
+                                   m_plane_default_values[p],
+                                   getBackgroundPlane(p));
     std::copy_n(m_planes[p], m_plane_sizes[p], composed->data() + base);
     base += m_plane_sizes[p];
   }
@@ -23,7 +23,8 @@ class GridComposer : public Composer {
                const std::size_t,
                const std::size_t,
                const hisui::config::VideoScaler&,
-               const libyuv::FilterMode);
+               const libyuv::FilterMode,
+               const std::array<unsigned char, 3>&);
 
   ~GridComposer();
 
@@ -11,6 +11,7 @@
 #include <string>
 #include <utility>
 
+#include "config.hpp"
 #include "util/interval.hpp"
 #include "video/sequencer.hpp"
 #include "video/source.hpp"
@@ -20,8 +21,9 @@ namespace hisui::video {
 
 MultiChannelSequencer::MultiChannelSequencer(
     const std::vector<hisui::Archive>& normal_archives,
-    const std::vector<hisui::Archive>& preferred_archives) {
-  auto normal_result = make_sequence(normal_archives);
+    const std::vector<hisui::Archive>& preferred_archives,
+    const hisui::Config& config) {
+  auto normal_result = make_sequence(normal_archives, config);
 
   m_sequence = normal_result.sequence;
   m_size = std::size(m_sequence);
@@ -32,15 +34,18 @@ MultiChannelSequencer::MultiChannelSequencer(
   spdlog::debug("m_max_width x m_max_height: {} x {}", m_max_width,
                 m_max_height);
 
-  m_black_yuv_image = create_black_yuv_image(m_max_width, m_max_height);
+  // アーカイブがない区間は背景色で埋める
+  m_background_yuv_image = create_background_yuv_image(
+      m_max_width, m_max_height,
+      get_background_plane_values(config.background_color));
 
-  auto preferred_result = make_sequence(preferred_archives);
+  auto preferred_result = make_sequence(preferred_archives, config);
 
   m_preferred_sequence = preferred_result.sequence;
 }  // namespace hisui::video
 
 MultiChannelSequencer::~MultiChannelSequencer() {
-  delete m_black_yuv_image;
+  delete m_background_yuv_image;
 }
 
 SequencerGetYUVsResult MultiChannelSequencer::getYUVs(
@@ -64,7 +69,7 @@ SequencerGetYUVsResult MultiChannelSequencer::getYUVs(
         std::begin(*p.second), std::end(*p.second),
         [timestamp](const auto& s) { return s.second.isIn(timestamp); });
     if (it == std::end(*p.second)) {
-      (*yuvs)[i] = m_black_yuv_image;
+      (*yuvs)[i] = m_background_yuv_image;
     } else {
       (*yuvs)[i] = it->first->getYUV(it->second.getSubstructLower(timestamp));
     }
@@ -11,6 +11,7 @@
 namespace hisui {
 
 class Archive;
+class Config;
 
 }
 
@@ -20,15 +21,16 @@ class YUVImage;
 
 class MultiChannelSequencer : public Sequencer {
  public:
-  explicit MultiChannelSequencer(const std::vector<hisui::Archive>&,
-                                 const std::vector<hisui::Archive>&);
+  MultiChannelSequencer(const std::vector<hisui::Archive>&,
+                        const std::vector<hisui::Archive>&,
+                        const hisui::Config&);
   ~MultiChannelSequencer();
 
   SequencerGetYUVsResult getYUVs(std::vector<const YUVImage*>*,
                                  const std::uint64_t);
 
  private:
-  const YUVImage* m_black_yuv_image;
+  const YUVImage* m_background_yuv_image;
   std::vector<
       std::pair<std::string, std::shared_ptr<std::vector<SourceAndInterval>>>>
       m_preferred_sequence;
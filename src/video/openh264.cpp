@@ -1,5 +1,6 @@
 #include "video/openh264.hpp"
 
+#include <codec/api/svc/codec_app_def.h>
 #include <codec/api/svc/codec_def.h>
 
 #include <algorithm>
@@ -7,6 +8,7 @@
 #include <cstddef>
 #include <cstdint>
 
+#include "config.hpp"
 #include "video/yuv.hpp"
 
 namespace hisui::video {
@@ -43,4 +45,23 @@ void update_yuv_image_by_openh264_buffer_info(
   }
 }
 
+::ERROR_CON_IDC get_openh264_error_con_idc(
+    const hisui::config::OpenH264ErrorConcealment error_concealment) {
+  switch (error_concealment) {
+    case hisui::config::OpenH264ErrorConcealment::Disable:
+      return ::ERROR_CON_DISABLE;
+    case hisui::config::OpenH264ErrorConcealment::FrameCopy:
+      return ::ERROR_CON_FRAME_COPY;
+    case hisui::config::OpenH264ErrorConcealment::SliceCopy:
+      return ::ERROR_CON_SLICE_COPY;
+    case hisui::config::OpenH264ErrorConcealment::FrameCopyCrossIDR:
+      return ::ERROR_CON_FRAME_COPY_CROSS_IDR;
+    case hisui::config::OpenH264ErrorConcealment::SliceCopyCrossIDR:
+      return ::ERROR_CON_SLICE_COPY_CROSS_IDR;
+    case hisui::config::OpenH264ErrorConcealment::SliceMVCopyCrossIDR:
+      return ::ERROR_CON_SLICE_MV_COPY_CROSS_IDR;
+  }
+  return ::ERROR_CON_DISABLE;
+}
+
 }  // namespace hisui::video
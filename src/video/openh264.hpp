@@ -1,11 +1,17 @@
 #pragma once
 
+#include <codec/api/svc/codec_app_def.h>
 #include <codec/api/svc/codec_def.h>
 
+#include "config.hpp"
+
 namespace hisui::video {
 
 class YUVImage;
 
 void update_yuv_image_by_openh264_buffer_info(YUVImage*, const ::SBufferInfo&);
 
+::ERROR_CON_IDC get_openh264_error_con_idc(
+    const hisui::config::OpenH264ErrorConcealment);
+
 }  // namespace hisui::video
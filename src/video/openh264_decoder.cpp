@@ -12,6 +12,7 @@
 #include <memory>
 #include <stdexcept>
 
+#include "config.hpp"
 #include "report/reporter.hpp"
 #include "video/openh264.hpp"
 #include "video/openh264_handler.hpp"
@@ -20,8 +21,11 @@
 
 namespace hisui::video {
 
-OpenH264Decoder::OpenH264Decoder(hisui::webm::input::VideoContext* t_webm)
-    : Decoder(t_webm) {
+OpenH264Decoder::OpenH264Decoder(hisui::webm::input::VideoContext* t_webm,
+                                 const hisui::Config& config)
+    : Decoder(t_webm, config),
+      m_error_concealment(config.openh264_error_concealment !=
+                          hisui::config::OpenH264ErrorConcealment::Disable) {
   const auto create_decoder_ret =
       OpenH264Handler::getInstance().createDecoder(&m_decoder);
   if (create_decoder_ret != 0 || m_decoder == nullptr) {
@@ -33,7 +37,8 @@ OpenH264Decoder::OpenH264Decoder(hisui::webm::input::VideoContext* t_webm)
   param.pFileNameRestructed = nullptr;
   param.uiCpuLoad = 100;  // openh264 のソースをみると利用していないようだ
   param.uiTargetDqLayer = 1;
-  param.eEcActiveIdc = ::ERROR_CON_DISABLE;
+  param.eEcActiveIdc =
+      get_openh264_error_con_idc(config.openh264_error_concealment);
   param.bParseOnly = false;
   param.sVideoProperty.eVideoBsType = ::VIDEO_BITSTREAM_AVC;
   const auto decoder_initialize_ret = m_decoder->Initialize(&param);
@@ -75,12 +80,12 @@ OpenH264Decoder::~OpenH264Decoder() {
 const YUVImage* OpenH264Decoder::getImage(const std::uint64_t timestamp) {
   // 非対応 WebM or 時間超過
   if (!m_webm || m_is_time_over) {
-    return m_black_yuv_image;
+    return m_background_yuv_image;
   }
   // 時間超過した
   if (m_duration <= timestamp) {
     m_is_time_over = true;
-    return m_black_yuv_image;
+    return m_background_yuv_image;
   }
   updateImage(timestamp);
   return m_current_yuv_image.get();
@@ -118,10 +123,17 @@ void OpenH264Decoder::updateImageByTimestamp(const std::uint64_t timestamp) {
           m_webm->getBuffer(), static_cast<int>(m_webm->getBufferSize()),
           m_tmp_yuv, &buffer_info);
       if (ret != 0) {
-        spdlog::error(
-            "OpenH264Decoder DecodeFrameNoDelay failed: error_code={}", ret);
-        throw std::runtime_error(fmt::format(
-            "m_decoder->DecodeFrameNoDelay() failed: error_code={}", ret));
+        if (!m_error_concealment) {
+          spdlog::error(
+              "OpenH264Decoder DecodeFrameNoDelay failed: error_code={}", ret);
+          throw std::runtime_error(fmt::format(
+              "m_decoder->DecodeFrameNoDelay() failed: error_code={}", ret));
+        }
+        // 出力されたフレームがあれば利用し, なければ直前のフレームを維持する
+        spdlog::warn(
+            "OpenH264Decoder DecodeFrameNoDelay failed: error_code={}, "
+            "timestamp={}",
+            ret, m_webm->getTimestamp());
       }
       m_next_timestamp = static_cast<std::uint64_t>(m_webm->getTimestamp());
       if (buffer_info.iBufferStatus == 1) {
@@ -7,6 +7,12 @@
 
 class ISVCDecoder;
 
+namespace hisui {
+
+class Config;
+
+}
+
 namespace hisui::webm::input {
 
 class VideoContext;
@@ -19,7 +25,7 @@ class YUVImage;
 
 class OpenH264Decoder : public Decoder {
  public:
-  explicit OpenH264Decoder(hisui::webm::input::VideoContext*);
+  OpenH264Decoder(hisui::webm::input::VideoContext*, const hisui::Config&);
   ~OpenH264Decoder();
 
   const YUVImage* getImage(const std::uint64_t);
@@ -32,6 +38,7 @@ class OpenH264Decoder : public Decoder {
   std::shared_ptr<YUVImage> m_next_yuv_image = nullptr;
   std::uint8_t* m_tmp_yuv[3];
   bool m_report_enabled = false;
+  bool m_error_concealment = false;
 
   void updateImage(const std::uint64_t);
 
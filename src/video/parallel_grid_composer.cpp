@@ -20,7 +20,8 @@ ParallelGridComposer::ParallelGridComposer(
     const std::size_t t_size,
     const std::size_t t_colomn,
     const hisui::config::VideoScaler& scaler_type,
-    const libyuv::FilterMode filter_mode)
+    const libyuv::FilterMode filter_mode,
+    const std::array<unsigned char, 3>& t_background)
     : m_single_width(t_single_width),
       m_single_height(t_single_height),
       m_size(t_size),
@@ -33,7 +34,7 @@ ParallelGridComposer::ParallelGridComposer(
     switch (scaler_type) {
       case hisui::config::VideoScaler::PreserveAspectRatio:
         m_scalers.push_back(std::make_unique<PreserveAspectRatioScaler>(
-            m_single_width, m_single_height, filter_mode));
+            m_single_width, m_single_height, filter_mode, t_background));
         break;
       case hisui::config::VideoScaler::Simple:
         m_scalers.push_back(std::make_unique<SimpleScaler>(
@@ -58,9 +59,7 @@ ParallelGridComposer::ParallelGridComposer(
   m_single_plane_heights[0] = m_single_height;
   m_single_plane_heights[1] = (m_single_height + 1) >> 1;
   m_single_plane_heights[2] = m_single_plane_heights[1];
-  m_plane_default_values[0] = 0;
-  m_plane_default_values[1] = 128;
-  m_plane_default_values[2] = 128;
+  m_plane_default_values = t_background;
 }
 
 ParallelGridComposer::~ParallelGridComposer() {
@@ -82,7 +81,8 @@ void ParallelGridComposer::compose(std::vector<unsigned char>* composed,
     merge_yuv_planes_from_top_left(m_planes[0], m_plane_sizes[0], m_column,
                                    m_srcs[0], m_size, m_single_plane_widths[0],
                                    m_single_plane_heights[0],
-                                   m_plane_default_values[0]);
+                                   m_plane_default_values[0],
+                                   getBackgroundPlane(0));
 
     std::copy_n(m_planes[0], m_plane_sizes[0], composed->data());
   });
@@ -94,7 +94,8 @@ void ParallelGridComposer::compose(std::vector<unsigned char>* composed,
     merge_yuv_planes_from_top_left(m_planes[1], m_plane_sizes[1], m_column,
                                    m_srcs[1], m_size, m_single_plane_widths[1],
                                    m_single_plane_heights[1],
-                                   m_plane_default_values[1]);
+                                   m_plane_default_values[1],
+                                   getBackgroundPlane(1));
 
     std::copy_n(m_planes[1], m_plane_sizes[1],
                 composed->data() + m_plane_sizes[0]);
@@ -107,7 +108,8 @@ void ParallelGridComposer::compose(std::vector<unsigned char>* composed,
     merge_yuv_planes_from_top_left(m_planes[2], m_plane_sizes[2], m_column,
                                    m_srcs[2], m_size, m_single_plane_widths[2],
                                    m_single_plane_heights[2],
-                                   m_plane_default_values[2]);
+                                   m_plane_default_values[2],
+                                   getBackgroundPlane(2));
 
     std::copy_n(m_planes[2], m_plane_sizes[2],
                 composed->data() + m_plane_sizes[0] + m_plane_sizes[1]);
@@ -20,8 +20,11 @@ namespace hisui::video {
 PreserveAspectRatioScaler::PreserveAspectRatioScaler(
     const std::uint32_t t_width,
     const std::uint32_t t_height,
-    const libyuv::FilterMode t_filter_mode)
-    : Scaler(t_width, t_height), m_filter_mode(t_filter_mode) {
+    const libyuv::FilterMode t_filter_mode,
+    const std::array<unsigned char, 3>& t_margin_plane_values)
+    : Scaler(t_width, t_height),
+      m_filter_mode(t_filter_mode),
+      m_margin_plane_values(t_margin_plane_values) {
   m_intermediate = new YUVImage(m_width, m_height);
 }
 
@@ -81,7 +84,7 @@ const YUVImage* PreserveAspectRatioScaler::marginInHeightScale(
         fmt::format("I420Scale() failed: error_code={}", ret));
   }
 
-  m_scaled->setBlack();
+  m_scaled->fill(m_margin_plane_values);
 
   std::copy_n(m_intermediate->yuv[0],
               m_intermediate->getWidth(0) * m_intermediate->getHeight(0),
@@ -131,7 +134,7 @@ const YUVImage* PreserveAspectRatioScaler::marginInWidthScale(
         fmt::format("I420Scale() failed: error_code={}", ret));
   }
 
-  m_scaled->setBlack();
+  m_scaled->fill(m_margin_plane_values);
 
   for (std::uint32_t h = 0, m = m_intermediate->getHeight(0); h < m; ++h) {
     std::copy_n(
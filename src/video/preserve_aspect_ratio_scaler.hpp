@@ -1,5 +1,6 @@
 #pragma once
 
+#include <array>
 #include <cstdint>
 
 #include "libyuv/scale.h"
@@ -21,12 +22,14 @@ class PreserveAspectRatioScaler : public Scaler {
  public:
   PreserveAspectRatioScaler(const std::uint32_t,
                             const std::uint32_t,
-                            const libyuv::FilterMode);
+                            const libyuv::FilterMode,
+                            const std::array<unsigned char, 3>&);
   ~PreserveAspectRatioScaler();
   const YUVImage* scale(const YUVImage*);
 
  private:
   const libyuv::FilterMode m_filter_mode;
+  const std::array<unsigned char, 3> m_margin_plane_values;
   YUVImage* m_intermediate;
 
   const YUVImage* simpleScale(const YUVImage*);
@@ -11,6 +11,7 @@
 #include <iterator>
 #include <set>
 
+#include "config.hpp"
 #include "constants.hpp"
 #include "metadata.hpp"
 #include "util/interval.hpp"
@@ -32,7 +33,8 @@ std::size_t Sequencer::getSize() const {
   return m_size;
 }
 
-MakeSequenceResult make_sequence(const std::vector<hisui::Archive>& archives) {
+MakeSequenceResult make_sequence(const std::vector<hisui::Archive>& archives,
+                                 const hisui::Config& config) {
   MakeSequenceResult result;
   auto& sequence = result.sequence;
   auto& max_width = result.max_width;
@@ -45,7 +47,7 @@ MakeSequenceResult make_sequence(const std::vector<hisui::Archive>& archives) {
     const auto& path = archive.getPath();
     const auto extension = path.extension();
     if (extension == ".webm") {
-      source = new WebMSource(path.string());
+      source = new WebMSource(path.string(), config);
     } else if (image_extensions.contains(extension)) {
       source = new ImageSource(path.string());
     } else {
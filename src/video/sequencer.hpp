@@ -12,6 +12,7 @@
 namespace hisui {
 
 class Archive;
+class Config;
 
 }
 
@@ -55,6 +56,7 @@ struct MakeSequenceResult {
   std::uint32_t max_height = 0;
 };
 
-MakeSequenceResult make_sequence(const std::vector<hisui::Archive>&);
+MakeSequenceResult make_sequence(const std::vector<hisui::Archive>&,
+                                 const hisui::Config&);
 
 }  // namespace hisui::video
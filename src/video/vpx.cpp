@@ -43,6 +43,13 @@ VPXEncoderConfig::VPXEncoderConfig(const std::uint32_t t_width,
       tile_columns(config.libvp9_tile_columns),
       row_mt(config.libvp9_row_mt) {}
 
+VPXDecoderConfig::VPXDecoderConfig(const std::uint32_t t_fourcc,
+                                   const hisui::Config& config)
+    : fourcc(t_fourcc),
+      error_concealment(config.libvpx_error_concealment),
+      postproc_deblocking_level(config.libvpx_postproc_deblocking_level),
+      postproc_noise_level(config.libvpx_postproc_noise_level) {}
+
 void update_yuv_image_by_vpx_image(YUVImage* yuv_image,
                                    const vpx_image_t* vpx_image) {
   const std::array<int, 3> PLANES_YUV = {VPX_PLANE_Y, VPX_PLANE_U, VPX_PLANE_V};
@@ -90,6 +97,16 @@ void update_yuv_image_by_vpx_image(YUVImage* yuv_image,
   return nullptr;
 }
 
+bool supports_error_concealment(const std::uint32_t fourcc) {
+  // error concealment は VP8 かつ --enable-error-concealment でビルドした libvpx でのみ利用できる
+  const auto dx_algo = get_vpx_decode_codec_iface_by_fourcc(fourcc);
+  if (!dx_algo) {
+    return false;
+  }
+  const auto caps = ::vpx_codec_get_caps(dx_algo);
+  return (caps & VPX_CODEC_CAP_ERROR_CONCEALMENT) != 0;
+}
+
 std::uint32_t get_vpx_image_plane_width(const ::vpx_image_t* img,
                                         const int plane) {
   if (plane > 0 && img->x_chroma_shift > 0)
@@ -185,16 +202,53 @@ void create_vpx_codec_ctx_t_for_encoding(::vpx_codec_ctx_t* codec,
 }
 
 void create_vpx_codec_ctx_t_for_decoding(::vpx_codec_ctx_t* codec,
-                                         const std::uint32_t fourcc) {
-  const auto dx_algo = get_vpx_decode_codec_iface_by_fourcc(fourcc);
+                                         const VPXDecoderConfig& config) {
+  const auto dx_algo = get_vpx_decode_codec_iface_by_fourcc(config.fourcc);
 
   if (!dx_algo) {
     throw std::runtime_error("get_vpx_decode_codec_iface_by_fourcc() failed");
   }
 
-  if (::vpx_codec_dec_init(codec, dx_algo, nullptr, 0)) {
+  ::vpx_codec_flags_t flags = 0;
+  if (config.error_concealment) {
+    if (supports_error_concealment(config.fourcc)) {
+      flags |= VPX_CODEC_USE_ERROR_CONCEALMENT;
+    } else {
+      spdlog::debug("{} does not support error concealment",
+                    ::vpx_codec_iface_name(dx_algo));
+    }
+  }
+
+  int postproc_flags = 0;
+  if (config.postproc_deblocking_level > 0) {
+    postproc_flags |= ::VP8_DEBLOCK | ::VP8_DEMACROBLOCK;
+  }
+  if (config.postproc_noise_level > 0) {
+    postproc_flags |= ::VP8_ADDNOISE;
+  }
+  if (postproc_flags != 0) {
+    if (::vpx_codec_get_caps(dx_algo) & VPX_CODEC_CAP_POSTPROC) {
+      flags |= VPX_CODEC_USE_POSTPROC;
+    } else {
+      spdlog::debug("{} does not support post processing",
+                    ::vpx_codec_iface_name(dx_algo));
+      postproc_flags = 0;
+    }
+  }
+
+  if (::vpx_codec_dec_init(codec, dx_algo, nullptr, flags)) {
     throw std::runtime_error("vpx_codec_dec_init() failed");
   }
+
+  if (postproc_flags != 0) {
+    ::vp8_postproc_cfg_t postproc_cfg{
+        .post_proc_flag = postproc_flags,
+        .deblocking_level = static_cast<int>(config.postproc_deblocking_level),
+        .noise_level = static_cast<int>(config.postproc_noise_level)};
+    if (::vpx_codec_control(codec, VP8_SET_POSTPROC, &postproc_cfg)) {
+      throw std::runtime_error("vpx_codec_control(VP8_SET_POSTPROC) failed");
+    }
+  }
 }
 
 }  // namespace hisui::video
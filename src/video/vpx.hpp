@@ -40,11 +40,22 @@ class VPXEncoderConfig {
   const std::uint32_t row_mt;
 };
 
+class VPXDecoderConfig {
+ public:
+  VPXDecoderConfig(const std::uint32_t, const hisui::Config&);
+  const std::uint32_t fourcc;
+  const bool error_concealment;
+  const std::uint32_t postproc_deblocking_level;
+  const std::uint32_t postproc_noise_level;
+};
+
 void update_yuv_image_by_vpx_image(YUVImage*, const ::vpx_image_t*);
 
 vpx_codec_iface_t* get_vpx_decode_codec_iface_by_fourcc(const std::uint32_t);
 vpx_codec_iface_t* get_vpx_encode_codec_iface_by_fourcc(const std::uint32_t);
 
+bool supports_error_concealment(const std::uint32_t);
+
 std::uint32_t get_vpx_image_plane_width(const ::vpx_image_t*, const int);
 std::uint32_t get_vpx_image_plane_height(const ::vpx_image_t*, const int);
 
@@ -58,6 +69,6 @@ void create_vpx_codec_ctx_t_for_encoding(::vpx_codec_ctx_t*,
                                          const VPXEncoderConfig&);
 
 void create_vpx_codec_ctx_t_for_decoding(::vpx_codec_ctx_t*,
-                                         const std::uint32_t);
+                                         const VPXDecoderConfig&);
 
 }  // namespace hisui::video
@@ -12,6 +12,7 @@
 #include <memory>
 #include <stdexcept>
 
+#include "config.hpp"
 #include "constants.hpp"
 #include "report/reporter.hpp"
 #include "video/vpx.hpp"
@@ -20,9 +21,14 @@
 
 namespace hisui::video {
 
-VPXDecoder::VPXDecoder(hisui::webm::input::VideoContext* t_webm)
-    : Decoder(t_webm) {
-  create_vpx_codec_ctx_t_for_decoding(&m_codec, m_webm->getFourcc());
+VPXDecoder::VPXDecoder(hisui::webm::input::VideoContext* t_webm,
+                       const hisui::Config& config)
+    : Decoder(t_webm, config) {
+  create_vpx_codec_ctx_t_for_decoding(
+      &m_codec, VPXDecoderConfig(m_webm->getFourcc(), config));
+  // VP9 など error concealment に対応していない場合はデコードエラーを無視しない
+  m_error_concealment = config.libvpx_error_concealment &&
+                        supports_error_concealment(m_webm->getFourcc());
 
   m_current_yuv_image = new YUVImage(m_width, m_height);
 
@@ -61,12 +67,12 @@ VPXDecoder::~VPXDecoder() {
 const YUVImage* VPXDecoder::getImage(const std::uint64_t timestamp) {
   // 非対応 WebM or 時間超過
   if (!m_webm || m_is_time_over) {
-    return m_black_yuv_image;
+    return m_background_yuv_image;
   }
   // 時間超過した
   if (m_duration <= timestamp) {
     m_is_time_over = true;
-    return m_black_yuv_image;
+    return m_background_yuv_image;
   }
   // 極端におおきな fps にはしないと思うので m_current_yuv_image への変換は毎回やってよいと考えている
   updateVPXImage(timestamp);
@@ -103,7 +109,9 @@ void VPXDecoder::updateVPXImageByTimestamp(const std::uint64_t timestamp) {
                .height = get_vpx_image_plane_height(m_next_vpx_image, 0)});
         }
       }
-      ::vpx_img_free(m_current_vpx_image);
+      if (m_current_vpx_image != m_next_vpx_image) {
+        ::vpx_img_free(m_current_vpx_image);
+      }
     }
     m_current_vpx_image = m_next_vpx_image;
     m_current_timestamp = m_next_timestamp;
@@ -118,12 +126,20 @@ void VPXDecoder::updateVPXImageByTimestamp(const std::uint64_t timestamp) {
         if (detail != nullptr) {
           spdlog::warn("vpx_codec_decode() error detail: {}", detail);
         }
-        throw std::runtime_error(
-            fmt::format("vpx_codec_decode() failed: error_code={}", ret));
+        if (!m_error_concealment) {
+          throw std::runtime_error(
+              fmt::format("vpx_codec_decode() failed: error_code={}", ret));
+        }
       }
       m_next_vpx_image = ::vpx_codec_get_frame(&m_codec, &codec_iter);
       if (!m_next_vpx_image) {
-        throw std::runtime_error("vpx_codec_get_frame() failed");
+        if (!m_error_concealment) {
+          throw std::runtime_error("vpx_codec_get_frame() failed");
+        }
+        // 復元できなかったフレームは直前のフレームで置き換える
+        spdlog::warn("vpx_codec_get_frame() failed: timestamp={}",
+                     m_webm->getTimestamp());
+        m_next_vpx_image = m_current_vpx_image;
       }
       m_next_timestamp = static_cast<std::uint64_t>(m_webm->getTimestamp());
     } else {
@@ -7,6 +7,12 @@
 
 #include "video/decoder.hpp"
 
+namespace hisui {
+
+class Config;
+
+}
+
 namespace hisui::webm::input {
 
 class VideoContext;
@@ -19,7 +25,7 @@ class YUVImage;
 
 class VPXDecoder : public Decoder {
  public:
-  explicit VPXDecoder(hisui::webm::input::VideoContext*);
+  VPXDecoder(hisui::webm::input::VideoContext*, const hisui::Config&);
 
   ~VPXDecoder();
 
@@ -33,6 +39,7 @@ class VPXDecoder : public Decoder {
   ::vpx_image_t* m_next_vpx_image = nullptr;
   YUVImage* m_current_yuv_image = nullptr;
   bool m_report_enabled = false;
+  bool m_error_concealment = false;
 
   void updateVPXImage(const std::uint64_t);
 
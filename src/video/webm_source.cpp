@@ -7,6 +7,7 @@
 
 #include <stdexcept>
 
+#include "config.hpp"
 #include "constants.hpp"
 #include "video/decoder.hpp"
 #include "video/openh264_decoder.hpp"
@@ -17,7 +18,8 @@
 
 namespace hisui::video {
 
-WebMSource::WebMSource(const std::string& t_file_path) {
+WebMSource::WebMSource(const std::string& t_file_path,
+                       const hisui::Config& config) {
   m_webm = new hisui::webm::input::VideoContext(t_file_path);
   if (!m_webm->init()) {
     spdlog::info(
@@ -29,7 +31,9 @@ WebMSource::WebMSource(const std::string& t_file_path) {
     m_webm = nullptr;
     m_width = 320;
     m_height = 240;
-    m_black_yuv_image = create_black_yuv_image(m_width, m_height);
+    m_background_yuv_image = create_background_yuv_image(
+        m_width, m_height,
+        get_background_plane_values(config.background_color));
     return;
   }
 
@@ -40,16 +44,17 @@ WebMSource::WebMSource(const std::string& t_file_path) {
                 m_width, m_height);
 
   m_duration = static_cast<std::uint64_t>(m_webm->getDuration());
-  m_black_yuv_image = create_black_yuv_image(m_width, m_height);
+  m_background_yuv_image = create_background_yuv_image(
+      m_width, m_height, get_background_plane_values(config.background_color));
 
   switch (m_webm->getFourcc()) {
     case hisui::Constants::VP8_FOURCC: /* fall through */
     case hisui::Constants::VP9_FOURCC:
-      m_decoder = new VPXDecoder(m_webm);
+      m_decoder = new VPXDecoder(m_webm, config);
       break;
     case hisui::Constants::H264_FOURCC:
       if (OpenH264Handler::hasInstance()) {
-        m_decoder = new OpenH264Decoder(m_webm);
+        m_decoder = new OpenH264Decoder(m_webm, config);
         break;
       }
       throw std::runtime_error("openh264 library is not loaded");
@@ -68,14 +73,14 @@ WebMSource::~WebMSource() {
   if (m_decoder) {
     delete m_decoder;
   }
-  if (m_black_yuv_image) {
-    delete m_black_yuv_image;
+  if (m_background_yuv_image) {
+    delete m_background_yuv_image;
   }
 }
 
 const YUVImage* WebMSource::getYUV(const std::uint64_t timestamp) {
   if (!m_decoder) {
-    return m_black_yuv_image;
+    return m_background_yuv_image;
   }
   return m_decoder->getImage(timestamp);
 }
@@ -5,6 +5,12 @@
 
 #include "video/source.hpp"
 
+namespace hisui {
+
+class Config;
+
+}
+
 namespace hisui::webm::input {
 
 class VideoContext;
@@ -18,7 +24,7 @@ class YUVImage;
 
 class WebMSource : public Source {
  public:
-  explicit WebMSource(const std::string&);
+  WebMSource(const std::string&, const hisui::Config&);
   ~WebMSource();
   const YUVImage* getYUV(const std::uint64_t);
   std::uint32_t getWidth() const;
@@ -27,7 +33,7 @@ class WebMSource : public Source {
  private:
   hisui::webm::input::VideoContext* m_webm;
   hisui::video::Decoder* m_decoder = nullptr;
-  YUVImage* m_black_yuv_image = nullptr;
+  YUVImage* m_background_yuv_image = nullptr;
   std::uint32_t m_width;
   std::uint32_t m_height;
   std::uint64_t m_duration;
@@ -57,11 +57,15 @@ std::uint32_t YUVImage::getHeight(const int plane) const {
 }
 
 void YUVImage::setBlack() {
+  fill({0, 128, 128});
+}
+
+void YUVImage::fill(const std::array<unsigned char, 3>& plane_values) {
   for (std::size_t i = 0; i < 3; ++i) {
     std::fill(
         yuv[i],
         yuv[i] + getWidth(static_cast<int>(i)) * getHeight(static_cast<int>(i)),
-        i == 0 ? 0 : 128);
+        plane_values[i]);
   }
 }
 
@@ -72,6 +76,39 @@ YUVImage* create_black_yuv_image(const std::uint32_t width,
   return image;
 }
 
+YUVImage* create_background_yuv_image(
+    const std::uint32_t width,
+    const std::uint32_t height,
+    const std::array<unsigned char, 3>& plane_values) {
+  YUVImage* image = new YUVImage(width, height);
+  image->fill(plane_values);
+  return image;
+}
+
+std::array<unsigned char, 3> rgb_to_yuv(
+    const std::array<std::uint8_t, 3>& rgb) {
+  // デコーダーの出力や ImageSource と揃えるため BT.601 limited range で変換する
+  // 係数は libyuv の RGBToY/RGBToU/RGBToV と同じ
+  const int r = rgb[0];
+  const int g = rgb[1];
+  const int b = rgb[2];
+  const int y = (66 * r + 129 * g + 25 * b + 0x1080) >> 8;
+  const int u = (112 * b - 74 * g - 38 * r + 0x8080) >> 8;
+  const int v = (112 * r - 94 * g - 18 * b + 0x8080) >> 8;
+  return {static_cast<unsigned char>(y), static_cast<unsigned char>(u),
+          static_cast<unsigned char>(v)};
+}
+
+std::array<unsigned char, 3> get_background_plane_values(
+    const std::optional<std::array<std::uint8_t, 3>>& background_color) {
+  if (background_color) {
+    return rgb_to_yuv(*background_color);
+  }
+  // 指定がない場合は create_black_yuv_image() と同じ値にする
+  // #000000 を指定した場合 (Y=16) とは異なる
+  return {0, 128, 128};
+}
+
 void merge_yuv_planes_from_top_left(
     unsigned char* merged,
     const std::size_t merged_size,
@@ -80,8 +117,14 @@ void merge_yuv_planes_from_top_left(
     const std::size_t number_of_srcs,
     const std::uint32_t src_width,
     const std::uint32_t src_height,
-    const unsigned char default_value) {
-  std::fill_n(merged, merged_size, default_value);
+    const unsigned char default_value,
+    const unsigned char* base) {
+  // base (背景画像) があればその上に配置する
+  if (base) {
+    std::copy_n(base, merged_size, merged);
+  } else {
+    std::fill_n(merged, merged_size, default_value);
+  }
 
   for (std::size_t i = 0; i < number_of_srcs; ++i) {
     const auto c = i % column;
@@ -3,6 +3,7 @@
 #include <array>
 #include <cstddef>
 #include <cstdint>
+#include <optional>
 #include <vector>
 
 namespace hisui::video {
@@ -21,6 +22,7 @@ class YUVImage {
   std::uint32_t getHeight(const int) const;
 
   void setBlack();
+  void fill(const std::array<unsigned char, 3>&);
 
  private:
   std::uint32_t m_width;
@@ -28,6 +30,14 @@ class YUVImage {
 };
 
 YUVImage* create_black_yuv_image(const std::uint32_t, const std::uint32_t);
+YUVImage* create_background_yuv_image(const std::uint32_t,
+                                      const std::uint32_t,
+                                      const std::array<unsigned char, 3>&);
+
+std::array<unsigned char, 3> rgb_to_yuv(const std::array<std::uint8_t, 3>&);
+
+std::array<unsigned char, 3> get_background_plane_values(
+    const std::optional<std::array<std::uint8_t, 3>>&);
 
 void merge_yuv_planes_from_top_left(unsigned char*,
                                     const std::size_t,
@@ -36,6 +46,7 @@ void merge_yuv_planes_from_top_left(unsigned char*,
                                     const std::size_t,
                                     const std::uint32_t,
                                     const std::uint32_t,
-                                    const unsigned char);
+                                    const unsigned char,
+                                    const unsigned char* = nullptr);
 
 }  // namespace hisui::video
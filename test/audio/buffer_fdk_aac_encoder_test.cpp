@@ -0,0 +1,36 @@
+#include <cstdint>
+#include <queue>
+
+#include <boost/test/unit_test.hpp>
+
+#include "audio/buffer_fdk_aac_encoder.hpp"
+#include "frame.hpp"
+
+BOOST_AUTO_TEST_SUITE(buffer_fdk_aac_encoder)
+
+BOOST_AUTO_TEST_CASE(flush_short_input) {
+  std::queue<hisui::Frame> buffer;
+  hisui::audio::BufferFDKAACEncoder encoder(&buffer, {.bit_rate = 64000});
+
+  // 1 フレーム (1024 サンプル) に満たない入力でも flush で出力される
+  for (int i = 0; i < 1023; ++i) {
+    encoder.addSample(0, 0);
+  }
+  BOOST_REQUIRE(buffer.empty());
+
+  encoder.flush();
+  BOOST_REQUIRE(!buffer.empty());
+
+  // timestamp は出力したフレームごとに 1024 ずつ隙間なく進む
+  std::uint64_t expected_timestamp = 0;
+  while (!buffer.empty()) {
+    auto frame = buffer.front();
+    BOOST_REQUIRE_EQUAL(expected_timestamp, frame.timestamp);
+    BOOST_REQUIRE(frame.data_size > 0);
+    delete[] frame.data;
+    buffer.pop();
+    expected_timestamp += 1024;
+  }
+}
+
+BOOST_AUTO_TEST_SUITE_END()
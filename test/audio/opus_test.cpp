@@ -0,0 +1,99 @@
+#include <opus.h>
+#include <opus_defines.h>
+#include <opus_types.h>
+
+#include <array>
+#include <cmath>
+#include <cstddef>
+#include <numbers>
+
+#include <boost/test/unit_test.hpp>
+
+#include "audio/opus.hpp"
+#include "constants.hpp"
+
+BOOST_AUTO_TEST_SUITE(opus)
+
+BOOST_AUTO_TEST_CASE(create_opus_encoder_application_and_complexity) {
+  ::OpusEncoder* encoder = hisui::audio::create_opus_encoder(
+      {.application = OPUS_APPLICATION_VOIP, .complexity = 3});
+
+  int application;
+  BOOST_REQUIRE_EQUAL(
+      OPUS_OK,
+      ::opus_encoder_ctl(encoder, OPUS_GET_APPLICATION(&application)));
+  BOOST_REQUIRE_EQUAL(OPUS_APPLICATION_VOIP, application);
+
+  int complexity;
+  BOOST_REQUIRE_EQUAL(
+      OPUS_OK, ::opus_encoder_ctl(encoder, OPUS_GET_COMPLEXITY(&complexity)));
+  BOOST_REQUIRE_EQUAL(3, complexity);
+
+  ::opus_encoder_destroy(encoder);
+}
+
+BOOST_AUTO_TEST_CASE(create_opus_encoder_default_complexity) {
+  // complexity が負の値の場合は libopus のデフォルトのまま
+  int err;
+  ::OpusEncoder* reference = ::opus_encoder_create(
+      static_cast<::opus_int32>(hisui::Constants::PCM_SAMPLE_RATE), 2,
+      OPUS_APPLICATION_AUDIO, &err);
+  BOOST_REQUIRE_EQUAL(OPUS_OK, err);
+  int default_complexity;
+  ::opus_encoder_ctl(reference, OPUS_GET_COMPLEXITY(&default_complexity));
+  ::opus_encoder_destroy(reference);
+
+  ::OpusEncoder* encoder = hisui::audio::create_opus_encoder({});
+
+  int complexity;
+  BOOST_REQUIRE_EQUAL(
+      OPUS_OK, ::opus_encoder_ctl(encoder, OPUS_GET_COMPLEXITY(&complexity)));
+  BOOST_REQUIRE_EQUAL(default_complexity, complexity);
+
+  ::opus_encoder_destroy(encoder);
+}
+
+BOOST_AUTO_TEST_CASE(encode_silk_and_decode) {
+  // SIGNAL はヒントなので高いビットレートでは CELT が選ばれることがある.
+  // 帯域を狭帯域に制限し, ビットレートを下げて SILK になることを確認する
+  ::OpusEncoder* encoder = hisui::audio::create_opus_encoder(
+      {.bit_rate = 16000,
+       .max_bandwidth = OPUS_BANDWIDTH_NARROWBAND,
+       .signal = OPUS_SIGNAL_VOICE});
+  ::OpusDecoder* decoder = hisui::audio::create_opus_decoder({});
+
+  constexpr std::size_t frame_size = 960;  // 20ms
+  std::array<::opus_int16, frame_size * 2> pcm;
+  std::array<unsigned char, 4000> packet;
+  std::array<::opus_int16, frame_size * 2> decoded;
+
+  std::size_t sample = 0;
+  for (int n = 0; n < 10; ++n) {
+    for (std::size_t i = 0; i < frame_size; ++i, ++sample) {
+      const double t =
+          static_cast<double>(sample) / hisui::Constants::PCM_SAMPLE_RATE;
+      const auto v = static_cast<::opus_int16>(
+          8000 * std::sin(2 * std::numbers::pi * 440 * t));
+      pcm[i * 2] = v;
+      pcm[i * 2 + 1] = v;
+    }
+
+    const auto packet_size = ::opus_encode(
+        encoder, pcm.data(), static_cast<int>(frame_size), packet.data(),
+        static_cast<::opus_int32>(packet.size()));
+    BOOST_REQUIRE(packet_size > 0);
+
+    // TOC の config が 0-11 の場合は SILK のみ
+    BOOST_REQUIRE((packet[0] >> 3) < 12);
+
+    const auto decoded_size =
+        ::opus_decode(decoder, packet.data(), packet_size, decoded.data(),
+                      static_cast<int>(frame_size), 0);
+    BOOST_REQUIRE_EQUAL(static_cast<int>(frame_size), decoded_size);
+  }
+
+  ::opus_decoder_destroy(decoder);
+  ::opus_encoder_destroy(encoder);
+}
+
+BOOST_AUTO_TEST_SUITE_END()
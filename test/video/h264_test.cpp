@@ -1,8 +1,10 @@
-#include <boost/test/unit_test.hpp>
+#include <codec/api/svc/codec_app_def.h>
+#include <codec/api/svc/codec_def.h>
 
-#include "codec_def.h"
+#include <boost/test/unit_test.hpp>
 
-#include "video/h264.hpp"
+#include "config.hpp"
+#include "video/openh264.hpp"
 #include "video/yuv.hpp"
 
 BOOST_AUTO_TEST_SUITE(h264)
@@ -90,4 +92,28 @@ BOOST_AUTO_TEST_CASE(update_yuv_image_by_openh264_buffer_info_3) {
                                   buf2, buf2 + 2);
 }
 
+BOOST_AUTO_TEST_CASE(get_openh264_error_con_idc) {
+  using hisui::config::OpenH264ErrorConcealment;
+  using hisui::video::get_openh264_error_con_idc;
+
+  BOOST_REQUIRE_EQUAL(::ERROR_CON_DISABLE,
+                      get_openh264_error_con_idc(
+                          OpenH264ErrorConcealment::Disable));
+  BOOST_REQUIRE_EQUAL(::ERROR_CON_FRAME_COPY,
+                      get_openh264_error_con_idc(
+                          OpenH264ErrorConcealment::FrameCopy));
+  BOOST_REQUIRE_EQUAL(::ERROR_CON_SLICE_COPY,
+                      get_openh264_error_con_idc(
+                          OpenH264ErrorConcealment::SliceCopy));
+  BOOST_REQUIRE_EQUAL(::ERROR_CON_FRAME_COPY_CROSS_IDR,
+                      get_openh264_error_con_idc(
+                          OpenH264ErrorConcealment::FrameCopyCrossIDR));
+  BOOST_REQUIRE_EQUAL(::ERROR_CON_SLICE_COPY_CROSS_IDR,
+                      get_openh264_error_con_idc(
+                          OpenH264ErrorConcealment::SliceCopyCrossIDR));
+  BOOST_REQUIRE_EQUAL(::ERROR_CON_SLICE_MV_COPY_CROSS_IDR,
+                      get_openh264_error_con_idc(
+                          OpenH264ErrorConcealment::SliceMVCopyCrossIDR));
+}
+
 BOOST_AUTO_TEST_SUITE_END()
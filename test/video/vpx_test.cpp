@@ -1,10 +1,86 @@
+#include <vpx/vpx_codec.h>
+#include <vpx/vpx_decoder.h>
+#include <vpx/vpx_encoder.h>
 #include <vpx/vpx_image.h>
 
+#include <algorithm>
+#include <cstddef>
+#include <cstdint>
+#include <vector>
+
 #include <boost/test/unit_test.hpp>
 
+#include "config.hpp"
+#include "constants.hpp"
 #include "video/vpx.hpp"
 #include "video/yuv.hpp"
 
+namespace {
+
+// フレームごとに変化する模様を encode して, 得られたフレームのデータを返す
+std::vector<std::vector<std::uint8_t>> encode_frames(
+    const hisui::config::OutVideoCodec codec_type,
+    const std::uint32_t width,
+    const std::uint32_t height,
+    const std::uint32_t number_of_frames) {
+  hisui::Config config;
+  config.out_video_codec = codec_type;
+  config.out_video_bit_rate = 500;
+  hisui::video::VPXEncoderConfig vpx_config(width, height, config);
+
+  ::vpx_codec_ctx_t codec;
+  ::vpx_codec_enc_cfg_t cfg;
+  hisui::video::create_vpx_codec_ctx_t_for_encoding(&codec, &cfg, vpx_config);
+
+  ::vpx_image_t* img =
+      ::vpx_img_alloc(nullptr, VPX_IMG_FMT_I420, width, height, 0);
+
+  std::vector<std::vector<std::uint8_t>> frames;
+  const auto get_frames = [&codec, &frames]() {
+    ::vpx_codec_iter_t iter = nullptr;
+    const ::vpx_codec_cx_pkt_t* pkt = nullptr;
+    bool got_pkts = false;
+    while ((pkt = ::vpx_codec_get_cx_data(&codec, &iter)) != nullptr) {
+      got_pkts = true;
+      if (pkt->kind == VPX_CODEC_CX_FRAME_PKT) {
+        const auto buf = static_cast<const std::uint8_t*>(pkt->data.frame.buf);
+        frames.emplace_back(buf, buf + pkt->data.frame.sz);
+      }
+    }
+    return got_pkts;
+  };
+
+  for (std::uint32_t i = 0; i < number_of_frames; ++i) {
+    for (std::uint32_t y = 0; y < height; ++y) {
+      for (std::uint32_t x = 0; x < width; ++x) {
+        img->planes[VPX_PLANE_Y]
+                   [y * static_cast<std::uint32_t>(img->stride[VPX_PLANE_Y]) +
+                    x] = static_cast<unsigned char>((x * 4 + y * 2 + i * 16));
+      }
+    }
+    for (const int plane : {VPX_PLANE_U, VPX_PLANE_V}) {
+      std::fill_n(img->planes[plane],
+                  static_cast<std::uint32_t>(img->stride[plane]) *
+                      ((height + 1) >> 1),
+                  128);
+    }
+    BOOST_REQUIRE_EQUAL(VPX_CODEC_OK,
+                        ::vpx_codec_encode(&codec, img, i, 1, 0,
+                                           VPX_DL_REALTIME));
+    get_frames();
+  }
+  while (::vpx_codec_encode(&codec, nullptr, -1, 1, 0, VPX_DL_REALTIME) ==
+             VPX_CODEC_OK &&
+         get_frames()) {
+  }
+
+  ::vpx_img_free(img);
+  ::vpx_codec_destroy(&codec);
+  return frames;
+}
+
+}  // namespace
+
 BOOST_AUTO_TEST_SUITE(vpx)
 
 BOOST_AUTO_TEST_CASE(update_yuv_image_by_vpx_image_1) {
@@ -76,4 +152,121 @@ BOOST_AUTO_TEST_CASE(update_yuv_image_by_vpx_image_3) {
                                   buf2, buf2 + 2);
 }
 
+BOOST_AUTO_TEST_CASE(supports_error_concealment) {
+  // VP8 のみ error concealment に対応している
+  BOOST_REQUIRE(
+      hisui::video::supports_error_concealment(hisui::Constants::VP8_FOURCC));
+  BOOST_REQUIRE(
+      !hisui::video::supports_error_concealment(hisui::Constants::VP9_FOURCC));
+  BOOST_REQUIRE(
+      !hisui::video::supports_error_concealment(hisui::Constants::H264_FOURCC));
+}
+
+BOOST_AUTO_TEST_CASE(decode_truncated_vp8_frame_with_error_concealment) {
+  const auto frames =
+      encode_frames(hisui::config::OutVideoCodec::VP8, 64, 64, 2);
+  BOOST_REQUIRE_EQUAL(2U, std::size(frames));
+
+  BOOST_REQUIRE(
+      hisui::video::supports_error_concealment(hisui::Constants::VP8_FOURCC));
+
+  hisui::Config config;
+  config.libvpx_error_concealment = true;
+  ::vpx_codec_ctx_t codec;
+  hisui::video::create_vpx_codec_ctx_t_for_decoding(
+      &codec,
+      hisui::video::VPXDecoderConfig(hisui::Constants::VP8_FOURCC, config));
+
+  // error concealment は key frame を正常にデコードした後に有効になる
+  BOOST_REQUIRE_EQUAL(
+      VPX_CODEC_OK,
+      ::vpx_codec_decode(&codec, frames[0].data(),
+                         static_cast<unsigned int>(std::size(frames[0])),
+                         nullptr, 0));
+  ::vpx_codec_iter_t iter = nullptr;
+  BOOST_REQUIRE(::vpx_codec_get_frame(&codec, &iter) != nullptr);
+
+  // inter frame の first partition 以降を半分に切り詰める
+  const auto& inter_frame = frames[1];
+  BOOST_REQUIRE((inter_frame[0] & 1) == 1);
+  const std::size_t first_partition_size =
+      static_cast<std::size_t>(inter_frame[0] | (inter_frame[1] << 8) |
+                               (inter_frame[2] << 16)) >>
+      5;
+  const std::size_t header_size = 3 + first_partition_size;
+  BOOST_REQUIRE(header_size < std::size(inter_frame));
+  const std::size_t truncated_size =
+      header_size + (std::size(inter_frame) - header_size) / 2;
+
+  BOOST_REQUIRE_EQUAL(
+      VPX_CODEC_OK,
+      ::vpx_codec_decode(&codec, inter_frame.data(),
+                         static_cast<unsigned int>(truncated_size), nullptr,
+                         0));
+  iter = nullptr;
+  const ::vpx_image_t* img = ::vpx_codec_get_frame(&codec, &iter);
+  BOOST_REQUIRE(img != nullptr);
+  BOOST_REQUIRE_EQUAL(64U, img->d_w);
+  BOOST_REQUIRE_EQUAL(64U, img->d_h);
+
+  ::vpx_codec_destroy(&codec);
+}
+
+BOOST_AUTO_TEST_CASE(decode_with_postproc) {
+  for (const auto codec_type :
+       {hisui::config::OutVideoCodec::VP8, hisui::config::OutVideoCodec::VP9}) {
+    const auto fourcc = static_cast<std::uint32_t>(codec_type);
+    const auto frames = encode_frames(codec_type, 64, 64, 3);
+    BOOST_REQUIRE(!frames.empty());
+
+    // 後処理の有効/無効どちらでもデコードでき, 各フレームの Y plane を返す
+    const auto decode = [&frames, fourcc](const std::uint32_t level) {
+      hisui::Config config;
+      config.libvpx_postproc_deblocking_level = level;
+      config.libvpx_postproc_noise_level = level;
+      ::vpx_codec_ctx_t codec;
+      BOOST_REQUIRE_NO_THROW(hisui::video::create_vpx_codec_ctx_t_for_decoding(
+          &codec, hisui::video::VPXDecoderConfig(fourcc, config)));
+
+      std::vector<std::vector<std::uint8_t>> y_planes;
+      for (const auto& frame : frames) {
+        BOOST_REQUIRE_EQUAL(
+            VPX_CODEC_OK,
+            ::vpx_codec_decode(&codec, frame.data(),
+                               static_cast<unsigned int>(std::size(frame)),
+                               nullptr, 0));
+        ::vpx_codec_iter_t iter = nullptr;
+        const ::vpx_image_t* img = ::vpx_codec_get_frame(&codec, &iter);
+        BOOST_REQUIRE(img != nullptr);
+        BOOST_REQUIRE_EQUAL(64U, img->d_w);
+        BOOST_REQUIRE_EQUAL(64U, img->d_h);
+
+        std::vector<std::uint8_t> y_plane;
+        for (std::uint32_t y = 0; y < img->d_h; ++y) {
+          const auto row = img->planes[0] +
+                           static_cast<std::ptrdiff_t>(y) * img->stride[0];
+          y_plane.insert(std::end(y_plane), row, row + img->d_w);
+        }
+        y_planes.push_back(y_plane);
+      }
+
+      ::vpx_codec_destroy(&codec);
+      return y_planes;
+    };
+
+    const auto without_postproc = decode(0);
+    const auto with_postproc = decode(4);
+
+    // 後処理に対応していない libvpx では後処理が無視されるので比較しない
+    const auto dx_algo =
+        hisui::video::get_vpx_decode_codec_iface_by_fourcc(fourcc);
+    if (::vpx_codec_get_caps(dx_algo) & VPX_CODEC_CAP_POSTPROC) {
+      // noise level 4 は必ず画素を変える
+      for (std::size_t i = 0; i < std::size(frames); ++i) {
+        BOOST_REQUIRE(without_postproc[i] != with_postproc[i]);
+      }
+    }
+  }
+}
+
 BOOST_AUTO_TEST_SUITE_END()
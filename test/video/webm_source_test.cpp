@@ -0,0 +1,53 @@
+#include <algorithm>
+#include <array>
+#include <cstddef>
+#include <cstdint>
+#include <filesystem>
+#include <string>
+
+#include <boost/test/unit_test.hpp>
+
+#include "config.hpp"
+#include "video/webm_source.hpp"
+#include "video/yuv.hpp"
+#include "webm/output/context.hpp"
+
+BOOST_AUTO_TEST_SUITE(webm_source)
+
+BOOST_AUTO_TEST_CASE(no_video_track_returns_background) {
+  const auto file_path = (std::filesystem::temp_directory_path() /
+                          "hisui_webm_source_test_audio_only.webm")
+                             .string();
+  {
+    // 映像トラックのない (音声のみの) WebM を作る
+    hisui::webm::output::Context context(file_path);
+    context.init();
+    context.setAudioTrack(0, nullptr, 0);
+    const std::array<std::uint8_t, 1> frame{0};
+    context.addAudioFrame(frame.data(), frame.size(), 0);
+  }
+
+  hisui::Config config;
+  config.background_color = std::array<std::uint8_t, 3>{255, 0, 0};
+  const auto expected =
+      hisui::video::get_background_plane_values(config.background_color);
+
+  {
+    hisui::video::WebMSource source(file_path, config);
+    const auto yuv = source.getYUV(0);
+
+    BOOST_REQUIRE(yuv->checkWidthAndHeight(320, 240));
+    for (std::size_t i = 0; i < 3; ++i) {
+      const auto size = yuv->getWidth(static_cast<int>(i)) *
+                        yuv->getHeight(static_cast<int>(i));
+      BOOST_REQUIRE(std::all_of(yuv->yuv[i], yuv->yuv[i] + size,
+                                [&expected, i](const unsigned char v) {
+                                  return v == expected[i];
+                                }));
+    }
+  }
+
+  std::filesystem::remove(file_path);
+}
+
+BOOST_AUTO_TEST_SUITE_END()
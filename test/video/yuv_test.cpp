@@ -93,6 +93,23 @@ BOOST_AUTO_TEST_CASE(merge_yuv_planes_from_top_left_2x2c) {
   delete[] merged;
 }
 
+BOOST_AUTO_TEST_CASE(merge_yuv_planes_from_top_left_with_base) {
+  const unsigned char p1[2] = {1, 1};
+  const unsigned char p2[2] = {2, 2};
+  const unsigned char p3[2] = {3, 3};
+  const unsigned char base[8] = {10, 11, 12, 13, 14, 15, 16, 17};
+  std::vector<const unsigned char*> yuvs{p1, p2, p3};
+  unsigned char* merged = new unsigned char[8];
+
+  hisui::video::merge_yuv_planes_from_top_left(merged, 8, 2, yuvs, 3, 2, 1, 0,
+                                               base);
+  unsigned char expected[8] = {1, 1, 2, 2, 3, 3, 16, 17};
+
+  BOOST_REQUIRE_EQUAL_COLLECTIONS(expected, expected + 8, merged, merged + 8);
+
+  delete[] merged;
+}
+
 BOOST_AUTO_TEST_CASE(create_black_yuv_image_1) {
   hisui::video::YUVImage* yuv = hisui::video::create_black_yuv_image(4, 2);
 
@@ -167,4 +184,50 @@ BOOST_AUTO_TEST_CASE(YUVIMage_setWidthAndHeight_1) {
   delete yuv;
 }
 
+BOOST_AUTO_TEST_CASE(YUVImage_fill) {
+  hisui::video::YUVImage yuv(4, 2);
+  yuv.fill({82, 90, 240});
+
+  unsigned char expected_y[] = {82, 82, 82, 82, 82, 82, 82, 82};
+  unsigned char expected_u[] = {90, 90};
+  unsigned char expected_v[] = {240, 240};
+
+  BOOST_REQUIRE_EQUAL_COLLECTIONS(expected_y, expected_y + 8, yuv.yuv[0],
+                                  yuv.yuv[0] + 8);
+  BOOST_REQUIRE_EQUAL_COLLECTIONS(expected_u, expected_u + 2, yuv.yuv[1],
+                                  yuv.yuv[1] + 2);
+  BOOST_REQUIRE_EQUAL_COLLECTIONS(expected_v, expected_v + 2, yuv.yuv[2],
+                                  yuv.yuv[2] + 2);
+}
+
+BOOST_AUTO_TEST_CASE(rgb_to_yuv) {
+  const std::array<unsigned char, 3> black{16, 128, 128};
+  const auto black_yuv = hisui::video::rgb_to_yuv({0, 0, 0});
+  BOOST_REQUIRE_EQUAL_COLLECTIONS(std::begin(black), std::end(black),
+                                  std::begin(black_yuv), std::end(black_yuv));
+
+  const std::array<unsigned char, 3> white{235, 128, 128};
+  const auto white_yuv = hisui::video::rgb_to_yuv({255, 255, 255});
+  BOOST_REQUIRE_EQUAL_COLLECTIONS(std::begin(white), std::end(white),
+                                  std::begin(white_yuv), std::end(white_yuv));
+
+  const std::array<unsigned char, 3> red{82, 90, 240};
+  const auto red_yuv = hisui::video::rgb_to_yuv({255, 0, 0});
+  BOOST_REQUIRE_EQUAL_COLLECTIONS(std::begin(red), std::end(red),
+                                  std::begin(red_yuv), std::end(red_yuv));
+}
+
+BOOST_AUTO_TEST_CASE(get_background_plane_values) {
+  const std::array<unsigned char, 3> unset{0, 128, 128};
+  const auto unset_yuv = hisui::video::get_background_plane_values({});
+  BOOST_REQUIRE_EQUAL_COLLECTIONS(std::begin(unset), std::end(unset),
+                                  std::begin(unset_yuv), std::end(unset_yuv));
+
+  const std::array<unsigned char, 3> white{235, 128, 128};
+  const auto white_yuv = hisui::video::get_background_plane_values(
+      std::array<std::uint8_t, 3>{255, 255, 255});
+  BOOST_REQUIRE_EQUAL_COLLECTIONS(std::begin(white), std::end(white),
+                                  std::begin(white_yuv), std::end(white_yuv));
+}
+
 BOOST_AUTO_TEST_SUITE_END()